@@ -5,7 +5,10 @@ use alloc::vec::Vec;
 use core::fmt::Debug;
 use core::ops::{Bound, RangeBounds};
 
-use crate::TextStorage;
+use crate::{
+    EditBehavior, EditableTextStorage, SpanEditAction, TextEdit, TextEditError, TextRange,
+    TextSize, TextStorage,
+};
 
 /// The errors that might happen as a result of [applying] an attribute.
 ///
@@ -20,11 +23,84 @@ pub enum ApplyAttributeError {
     InvalidBounds,
 }
 
+/// The errors that might happen as a result of [deleting] from an
+/// [`AttributedText`].
+///
+/// [deleting]: AttributedText::delete
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DeleteError {
+    /// The range given was out of bounds.
+    InvalidRange,
+}
+
+/// The errors that might happen as a result of [inserting] into an
+/// [`AttributedText`].
+///
+/// [inserting]: AttributedText::insert
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum InsertError {
+    /// The index given was out of bounds.
+    InvalidIndex,
+}
+
+/// Resolve a pair of [`Bound`]s into a concrete [`TextRange`], treating `Unbounded` as the edge of a container of `container_length`.
+fn bounds_to_range(
+    start_bound: Bound<TextSize>,
+    end_bound: Bound<TextSize>,
+    container_length: TextSize,
+) -> TextRange {
+    let start = match start_bound {
+        Bound::Included(start) => start,
+        Bound::Excluded(start) => start + TextSize::new(1),
+        Bound::Unbounded => TextSize::new(0),
+    };
+    let end = match end_bound {
+        Bound::Included(end) => end + TextSize::new(1),
+        Bound::Excluded(end) => end,
+        Bound::Unbounded => container_length,
+    };
+    TextRange::new(start, end)
+}
+
+/// Convert a `Bound<usize>` into the equivalent `Bound<TextSize>`.
+fn bound_to_text_size(bound: Bound<usize>) -> Bound<TextSize> {
+    match bound {
+        Bound::Included(value) => Bound::Included(TextSize::from(value)),
+        Bound::Excluded(value) => Bound::Excluded(TextSize::from(value)),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+/// Shift the offset carried by a [`Bound`], leaving `Unbounded` untouched
+/// since it carries no offset to shift.
+fn shift_bound(bound: Bound<TextSize>, f: impl FnOnce(TextSize) -> TextSize) -> Bound<TextSize> {
+    match bound {
+        Bound::Included(value) => Bound::Included(f(value)),
+        Bound::Excluded(value) => Bound::Excluded(f(value)),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+/// Shift a `TextSize` by a signed `delta`.
+fn shift_signed(value: TextSize, delta: isize) -> TextSize {
+    if delta >= 0 {
+        value + TextSize::from(delta as u32)
+    } else {
+        value - TextSize::from((-delta) as u32)
+    }
+}
+
+/// Shift the offset carried by a [`Bound`] by a signed `delta`, leaving
+/// `Unbounded` untouched since it carries no offset to shift.
+fn shift_bound_signed(bound: Bound<TextSize>, delta: isize) -> Bound<TextSize> {
+    shift_bound(bound, |value| shift_signed(value, delta))
+}
+
 /// An attribute and the bounds of the range to which it has been applied.
 #[derive(Debug)]
 struct RangedAttribute<Attr: Debug> {
-    start: Bound<usize>,
-    end: Bound<usize>,
+    start: Bound<TextSize>,
+    end: Bound<TextSize>,
     attribute: Attr,
 }
 
@@ -53,17 +129,20 @@ impl<T: Debug + TextStorage, Attr: Debug> AttributedText<T, Attr> {
     where
         R: RangeBounds<usize>,
     {
-        let rend = match range.end_bound() {
-            Bound::Included(end) => end + 1,
-            Bound::Excluded(end) => *end,
+        let start = bound_to_text_size(range.start_bound().cloned());
+        let end = bound_to_text_size(range.end_bound().cloned());
+
+        let rend = match end {
+            Bound::Included(end) => end + TextSize::new(1),
+            Bound::Excluded(end) => end,
             Bound::Unbounded => self.text.len(),
         };
         if rend > self.text.len() {
             return Err(ApplyAttributeError::InvalidBounds);
         }
         self.attributes.push(RangedAttribute {
-            start: range.start_bound().cloned(),
-            end: range.end_bound().cloned(),
+            start,
+            end,
             attribute,
         });
         Ok(())
@@ -72,10 +151,12 @@ impl<T: Debug + TextStorage, Attr: Debug> AttributedText<T, Attr> {
     /// Get an iterator over the attributes that apply at the given `index`.
     ///
     /// This doesn't handle conflicting attributes, it just reports everything.
+    /// To instead resolve the overlaps into non-overlapping runs, use
+    /// [`resolved_runs`].
     ///
-    /// TODO: Decide if this should also return the range bounds, and if so,
-    /// should it return them as `Bound` or as the resolved `usize` values.
+    /// [`resolved_runs`]: AttributedText::resolved_runs
     pub fn attributes_at(&self, index: usize) -> impl Iterator<Item = &Attr> {
+        let index = TextSize::from(index);
         self.attributes.iter().filter_map(move |ra| {
             if (ra.start, ra.end).contains(&index) {
                 Some(&ra.attribute)
@@ -88,59 +169,392 @@ impl<T: Debug + TextStorage, Attr: Debug> AttributedText<T, Attr> {
     /// Get an iterator over the attributes that apply to the given `range`.
     ///
     /// This doesn't handle conflicting attributes, it just reports everything.
+    /// To instead resolve the overlaps into non-overlapping runs, use
+    /// [`resolved_runs_for_range`].
     ///
-    /// TODO: Decide if this should also return the range bounds, and if so,
-    /// should it return them as `Bound` or as the resolved `usize` values.
+    /// [`resolved_runs_for_range`]: AttributedText::resolved_runs_for_range
     pub fn attributes_for_range<R>(&self, range: R) -> impl Iterator<Item = &Attr>
     where
         R: RangeBounds<usize>,
     {
-        fn bounds_to_indices(
-            start_bound: Bound<usize>,
-            end_bound: Bound<usize>,
-            container_length: usize,
-        ) -> (usize, usize) {
-            let start = match start_bound {
-                Bound::Included(start) => start,
-                Bound::Excluded(start) => start + 1,
-                Bound::Unbounded => 0,
-            };
-            let end = match end_bound {
-                Bound::Included(end) => end + 1,
-                Bound::Excluded(end) => end,
-                Bound::Unbounded => container_length,
-            };
-            (start, end)
-        }
-
-        let (range_start, range_end) = bounds_to_indices(
-            range.start_bound().cloned(),
-            range.end_bound().cloned(),
+        let query = bounds_to_range(
+            bound_to_text_size(range.start_bound().cloned()),
+            bound_to_text_size(range.end_bound().cloned()),
             self.text.len(),
         );
 
         self.attributes.iter().filter_map(move |ra| {
-            let (attribute_start, attribute_end) =
-                bounds_to_indices(ra.start, ra.end, self.text.len());
+            let span = bounds_to_range(ra.start, ra.end, self.text.len());
 
-            if (attribute_start < range_end) && (attribute_end > range_start) {
+            if (span.start() < query.end()) && (span.end() > query.start()) {
                 Some(&ra.attribute)
             } else {
                 None
             }
         })
     }
+
+    /// Get an iterator over the maximal non-overlapping runs that make up
+    /// the whole text, each paired with the exact set of attributes active
+    /// over it.
+    ///
+    /// Unlike [`attributes_at`]/[`attributes_for_range`], which just report
+    /// every attribute that overlaps a point or range, this resolves
+    /// conflicting attributes into the runs a text layout or
+    /// syntax-highlight consumer actually needs to render: each run is the
+    /// widest sub-range over which the set of active attributes doesn't
+    /// change, with the attributes ordered by application order so layering
+    /// is deterministic.
+    ///
+    /// [`attributes_at`]: AttributedText::attributes_at
+    /// [`attributes_for_range`]: AttributedText::attributes_for_range
+    pub fn resolved_runs(&self) -> impl Iterator<Item = (TextRange, Vec<&Attr>)> {
+        self.resolved_runs_for_range(..)
+    }
+
+    /// As [`resolved_runs`], but covering only `range`: runs that extend past
+    /// either end of `range` are clipped to it.
+    ///
+    /// [`resolved_runs`]: AttributedText::resolved_runs
+    pub fn resolved_runs_for_range<R>(
+        &self,
+        range: R,
+    ) -> impl Iterator<Item = (TextRange, Vec<&Attr>)>
+    where
+        R: RangeBounds<usize>,
+    {
+        let query = bounds_to_range(
+            bound_to_text_size(range.start_bound().cloned()),
+            bound_to_text_size(range.end_bound().cloned()),
+            self.text.len(),
+        );
+
+        let mut boundaries = Vec::with_capacity(self.attributes.len() * 2 + 2);
+        boundaries.push(query.start());
+        boundaries.push(query.end());
+        for ra in &self.attributes {
+            let span = bounds_to_range(ra.start, ra.end, self.text.len());
+            if span.is_empty() {
+                // An empty span is never active over a run, so it must not
+                // split one.
+                continue;
+            }
+            boundaries.push(span.start().clamp(query.start(), query.end()));
+            boundaries.push(span.end().clamp(query.start(), query.end()));
+        }
+        boundaries.sort_unstable();
+        boundaries.dedup();
+
+        let runs: Vec<TextRange> = boundaries
+            .windows(2)
+            .map(|pair| TextRange::new(pair[0], pair[1]))
+            .filter(|run| !TextRange::is_empty(*run))
+            .collect();
+
+        let text_len = self.text.len();
+        runs.into_iter().map(move |run| {
+            let active: Vec<&Attr> = self
+                .attributes
+                .iter()
+                .filter(|ra| {
+                    let span = bounds_to_range(ra.start, ra.end, text_len);
+                    span.start() <= run.start() && span.end() >= run.end()
+                })
+                .map(|ra| &ra.attribute)
+                .collect();
+            (run, active)
+        })
+    }
+}
+
+impl<T: Debug + EditableTextStorage, Attr: Debug + EditBehavior> AttributedText<T, Attr> {
+    /// Remove the text in `deletion_range`, adjusting every stored span to
+    /// account for the shrunk text.
+    ///
+    /// A span entirely before `deletion_range` is left alone, and a span
+    /// entirely after it is shifted left to stay attached to the same text.
+    /// A span that overlaps `deletion_range` is
+    /// resolved using [`EditBehavior::on_edit`]: spans that fully cover the
+    /// deletion shrink around the gap, spans that only partially overlap it
+    /// keep whichever side survives the deletion, and spans whose
+    /// [`EditBehavior::on_edit`] is [`SpanEditAction::Remove`] are dropped.
+    pub fn delete(&mut self, deletion_range: impl Into<TextRange>) -> Result<(), DeleteError> {
+        let deletion_range = deletion_range.into();
+        let text_len = self.text.len();
+        if deletion_range.end() > text_len {
+            return Err(DeleteError::InvalidRange);
+        }
+
+        self.text.replace_range(deletion_range, "");
+        let deleted_len = deletion_range.len();
+
+        self.attributes.retain_mut(|ra| {
+            let span = bounds_to_range(ra.start, ra.end, text_len);
+
+            if span.end() <= deletion_range.start() {
+                // Completely before the deletion -- no change.
+                true
+            } else if span.start() >= deletion_range.end() {
+                // Completely after the deletion -- shift left.
+                ra.start = shift_bound(ra.start, |v| v - deleted_len);
+                ra.end = shift_bound(ra.end, |v| v - deleted_len);
+                true
+            } else {
+                match ra.attribute.on_edit() {
+                    SpanEditAction::Keep => {
+                        if span.start() < deletion_range.start() && span.end() > deletion_range.end() {
+                            // Span fully covers the deletion -- shrink the gap.
+                            ra.end = shift_bound(ra.end, |v| v - deleted_len);
+                            true
+                        } else if span.start() >= deletion_range.start() {
+                            // Deletion eats the span's head -- keep the
+                            // surviving suffix, shifted left to where the
+                            // deletion began.
+                            let new_start = deletion_range.start();
+                            let new_end = if span.end() > deletion_range.end() {
+                                new_start + (span.end() - deletion_range.end())
+                            } else {
+                                new_start
+                            };
+                            ra.start = Bound::Included(new_start);
+                            ra.end = Bound::Excluded(new_end);
+                            new_start < new_end
+                        } else {
+                            // Deletion eats the span's tail -- keep the
+                            // surviving prefix.
+                            let new_start = span.start();
+                            let new_end = deletion_range.start();
+                            ra.start = Bound::Included(new_start);
+                            ra.end = Bound::Excluded(new_end);
+                            new_start < new_end
+                        }
+                    }
+                    SpanEditAction::Remove => false,
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Insert `text` at byte offset `index`, adjusting every stored span so
+    /// it keeps covering the same text it did before the insertion.
+    ///
+    /// Spans strictly before `index` are left alone, spans strictly after are
+    /// shifted right, and spans that straddle `index` grow to cover the
+    /// inserted text. A span with a boundary exactly at `index` is resolved
+    /// using the [`Bound`] stored for that boundary: an `Included` boundary
+    /// has the inserted text join the span, while an `Excluded` boundary
+    /// keeps the span on the far side of the insertion.
+    pub fn insert(&mut self, index: impl Into<TextSize>, text: &str) -> Result<(), InsertError> {
+        let index = index.into();
+        let text_len = self.text.len();
+        if index > text_len {
+            return Err(InsertError::InvalidIndex);
+        }
+
+        self.text.replace_range(TextRange::empty(index), text);
+        let inserted_len = TextSize::of(text);
+
+        for ra in &mut self.attributes {
+            let span = bounds_to_range(ra.start, ra.end, text_len);
+            let mut start_delta = TextSize::new(0);
+            let mut end_delta = TextSize::new(0);
+
+            if index < span.start() {
+                start_delta = inserted_len;
+                end_delta = inserted_len;
+            } else if index <= span.end() {
+                if span.start() < index && index < span.end() {
+                    end_delta = inserted_len;
+                }
+                if index == span.start() {
+                    match ra.start {
+                        // Excluded start: the inserted text stays outside
+                        // the span, so the whole span is pushed right.
+                        Bound::Excluded(_) => {
+                            start_delta = inserted_len;
+                            end_delta = inserted_len;
+                        }
+                        // Included start (or Unbounded): the inserted text
+                        // joins the span.
+                        Bound::Included(_) | Bound::Unbounded => {
+                            end_delta = inserted_len;
+                        }
+                    }
+                }
+                if index == span.end() && matches!(ra.end, Bound::Included(_)) {
+                    end_delta = inserted_len;
+                }
+            }
+
+            ra.start = shift_bound(ra.start, |v| v + start_delta);
+            ra.end = shift_bound(ra.end, |v| v + end_delta);
+        }
+
+        Ok(())
+    }
+
+    /// Replace the text in `range` with `text`, adjusting spans as if the
+    /// range had been [deleted] and the replacement then [inserted] at the
+    /// same position.
+    ///
+    /// [deleted]: AttributedText::delete
+    /// [inserted]: AttributedText::insert
+    pub fn replace_range<R>(&mut self, range: R, text: &str) -> Result<(), DeleteError>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = bounds_to_range(
+            bound_to_text_size(range.start_bound().cloned()),
+            bound_to_text_size(range.end_bound().cloned()),
+            self.text.len(),
+        );
+
+        self.delete(range)?;
+        self.insert(range.start(), text)
+            .expect("start is within the text after the preceding delete");
+        Ok(())
+    }
+
+    /// Apply every [`Indel`] in `edit` to this text in one atomic operation,
+    /// remapping all attribute spans in a single pass.
+    ///
+    /// [`Indel`]s are applied to the underlying storage from the highest
+    /// offset down, so that an earlier indel's offsets are never
+    /// invalidated by a later one. For attribute spans, each span replays
+    /// the indels in ascending order using the same boundary-gravity and
+    /// overlap rules as [`insert`] and [`delete`]: a pure insertion
+    /// respects the [`Bound`] stored for the boundary it lands on, a
+    /// deletion nested inside a span shrinks the span around the gap, a
+    /// deletion that only partially overlaps a span keeps whichever side of
+    /// the span survives the deletion, and any intersecting indel on a span
+    /// whose [`EditBehavior::on_edit`] is [`SpanEditAction::Remove`] drops
+    /// the span entirely.
+    ///
+    /// [`Indel`]: crate::Indel
+    /// [`insert`]: AttributedText::insert
+    /// [`delete`]: AttributedText::delete
+    pub fn apply(&mut self, edit: TextEdit) -> Result<(), TextEditError> {
+        let text_len = self.text.len();
+        for indel in edit.indels() {
+            if indel.delete.end() > text_len {
+                return Err(TextEditError::OutOfBounds);
+            }
+        }
+
+        for indel in edit.indels().iter().rev() {
+            self.text.replace_range(indel.delete, &indel.insert);
+        }
+        let new_text_len = self.text.len();
+
+        self.attributes.retain_mut(|ra| {
+            let mut cur_start = ra.start;
+            let mut cur_end = ra.end;
+            let mut current_len = text_len;
+            let mut shift_so_far: isize = 0;
+            let mut dropped = false;
+
+            for indel in edit.indels() {
+                let span = bounds_to_range(cur_start, cur_end, current_len);
+                let local_start = shift_signed(indel.delete.start(), shift_so_far);
+                let local_end = shift_signed(indel.delete.end(), shift_so_far);
+
+                if indel.delete.is_empty() {
+                    // Pure insertion: route through the same Bound-aware
+                    // gravity rules as `insert`.
+                    if local_start < span.start() {
+                        cur_start = shift_bound_signed(cur_start, indel.delta());
+                        cur_end = shift_bound_signed(cur_end, indel.delta());
+                    } else if local_start <= span.end() {
+                        if span.start() < local_start && local_start < span.end() {
+                            cur_end = shift_bound_signed(cur_end, indel.delta());
+                        }
+                        if local_start == span.start() {
+                            match cur_start {
+                                Bound::Excluded(_) => {
+                                    cur_start = shift_bound_signed(cur_start, indel.delta());
+                                    cur_end = shift_bound_signed(cur_end, indel.delta());
+                                }
+                                Bound::Included(_) | Bound::Unbounded => {
+                                    cur_end = shift_bound_signed(cur_end, indel.delta());
+                                }
+                            }
+                        }
+                        if local_start == span.end() && matches!(cur_end, Bound::Included(_)) {
+                            cur_end = shift_bound_signed(cur_end, indel.delta());
+                        }
+                    }
+                } else if local_end <= span.start() {
+                    // Entirely before the span -- shift both boundaries.
+                    cur_start = shift_bound_signed(cur_start, indel.delta());
+                    cur_end = shift_bound_signed(cur_end, indel.delta());
+                } else if local_start >= span.end() {
+                    // Entirely after the span -- the remaining indels are
+                    // sorted after this one, so none of them matter either.
+                    break;
+                } else if matches!(ra.attribute.on_edit(), SpanEditAction::Remove) {
+                    dropped = true;
+                    break;
+                } else if span.start() <= local_start && local_end <= span.end() {
+                    // Deletion entirely within the span -- shrink around the
+                    // gap (the span's start is unaffected, since nothing
+                    // before the deletion moved).
+                    cur_end = shift_bound_signed(cur_end, indel.delta());
+                } else if local_start < span.start() {
+                    // Deletion eats the span's head -- keep the surviving
+                    // suffix, landing right after the inserted replacement
+                    // text.
+                    let new_start = shift_signed(local_end, indel.delta());
+                    let new_end = if span.end() > local_end {
+                        shift_signed(span.end(), indel.delta())
+                    } else {
+                        new_start
+                    };
+                    cur_start = Bound::Included(new_start);
+                    cur_end = Bound::Excluded(new_end);
+                } else {
+                    // Deletion eats the span's tail -- keep the surviving
+                    // prefix, exactly like `delete` does for a single edit.
+                    cur_start = Bound::Included(span.start());
+                    cur_end = Bound::Excluded(local_start);
+                }
+
+                shift_so_far += indel.delta();
+                current_len = shift_signed(current_len, indel.delta());
+            }
+
+            if dropped {
+                return false;
+            }
+
+            ra.start = cur_start;
+            ra.end = cur_end;
+            let new_span = bounds_to_range(ra.start, ra.end, new_text_len);
+            new_span.start() < new_span.end()
+        });
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{ApplyAttributeError, AttributedText};
+    use crate::{EditBehavior, TextEdit, TextRange};
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
 
-    #[derive(Debug)]
+    #[derive(Debug, PartialEq)]
     enum TestAttribute {
         A,
+        B,
     }
 
+    impl EditBehavior for TestAttribute {}
+
     #[test]
     fn bad_range_for_apply_attribute() {
         let t = "Hello!";
@@ -153,4 +567,155 @@ mod tests {
             Err(ApplyAttributeError::InvalidBounds)
         );
     }
+
+    #[test]
+    fn insert_grows_span_that_straddles_index() {
+        let mut at = AttributedText::new(String::from("Hello!"));
+        at.apply_attribute(0..5, TestAttribute::A).unwrap();
+
+        at.insert(2usize, "---").unwrap();
+
+        assert_eq!(at.text, "He---llo!");
+        assert_eq!(at.attributes_for_range(..).count(), 1);
+        assert!(at.attributes_at(2).next().is_some());
+        assert!(at.attributes_at(7).next().is_some());
+    }
+
+    #[test]
+    fn replace_range_deletes_then_inserts() {
+        let mut at = AttributedText::new(String::from("Hello World"));
+        at.apply_attribute(0..11, TestAttribute::A).unwrap();
+
+        at.replace_range(2..7, "Y").unwrap();
+
+        assert_eq!(at.text, "HeYorld");
+        assert!(at.attributes_at(6).next().is_some());
+    }
+
+    #[test]
+    fn delete_keeps_span_tail_when_deletion_eats_the_head() {
+        let mut at = AttributedText::new(String::from("0123456789"));
+        at.apply_attribute(5..10, TestAttribute::A).unwrap();
+
+        at.delete(0..7).unwrap();
+
+        assert_eq!(at.text, "789");
+        assert_eq!(at.attributes_for_range(..).count(), 1);
+        assert!(at.attributes_at(0).next().is_some());
+    }
+
+    #[test]
+    fn apply_remaps_spans_for_every_indel_in_one_pass() {
+        let mut at = AttributedText::new(String::from("Hello World"));
+        at.apply_attribute(0..5, TestAttribute::A).unwrap();
+        at.apply_attribute(6..11, TestAttribute::A).unwrap();
+
+        let mut builder = TextEdit::builder();
+        builder.replace(0..5, "Hi".to_string());
+        builder.insert(11usize, "!".to_string());
+        let edit = builder.finish().unwrap();
+
+        at.apply(edit).unwrap();
+
+        assert_eq!(at.text, "Hi World!");
+        assert!(at.attributes_at(1).next().is_some());
+        assert!(at.attributes_at(7).next().is_some());
+    }
+
+    #[test]
+    fn apply_clamps_keep_span_on_partial_overlap() {
+        let mut at = AttributedText::new(String::from("0123456789"));
+        at.apply_attribute(2..8, TestAttribute::A).unwrap();
+
+        let mut builder = TextEdit::builder();
+        builder.replace(5..10, "Z".to_string());
+        let edit = builder.finish().unwrap();
+
+        at.apply(edit).unwrap();
+
+        assert_eq!(at.text, "01234Z");
+        assert!(at.attributes_at(2).next().is_some());
+        assert!(at.attributes_at(4).next().is_some());
+        assert!(at.attributes_at(5).next().is_none());
+    }
+
+    #[test]
+    fn apply_keeps_span_tail_when_replacement_eats_the_head() {
+        let mut at = AttributedText::new(String::from("0123456789"));
+        at.apply_attribute(5..10, TestAttribute::A).unwrap();
+
+        let mut builder = TextEdit::builder();
+        builder.replace(0..7, "X".to_string());
+        let edit = builder.finish().unwrap();
+
+        at.apply(edit).unwrap();
+
+        assert_eq!(at.text, "X789");
+        assert_eq!(at.attributes_for_range(..).count(), 1);
+        assert!(at.attributes_at(1).next().is_some());
+    }
+
+    #[test]
+    fn apply_pure_insertion_matches_insert_boundary_gravity() {
+        let mut via_insert = AttributedText::new(String::from("Hello!"));
+        via_insert.apply_attribute(0..5, TestAttribute::A).unwrap();
+        via_insert.insert(0usize, "--").unwrap();
+
+        let mut via_apply = AttributedText::new(String::from("Hello!"));
+        via_apply.apply_attribute(0..5, TestAttribute::A).unwrap();
+        let mut builder = TextEdit::builder();
+        builder.insert(0usize, "--".to_string());
+        let edit = builder.finish().unwrap();
+        via_apply.apply(edit).unwrap();
+
+        assert_eq!(via_insert.text, via_apply.text);
+        assert_eq!(
+            via_insert.attributes_at(0).count(),
+            via_apply.attributes_at(0).count()
+        );
+        assert_eq!(
+            via_insert.attributes_at(6).count(),
+            via_apply.attributes_at(6).count()
+        );
+    }
+
+    #[test]
+    fn resolved_runs_splits_on_every_span_boundary() {
+        let mut at = AttributedText::new("Hello World");
+        at.apply_attribute(0..11, TestAttribute::A).unwrap();
+        at.apply_attribute(6..11, TestAttribute::B).unwrap();
+
+        let runs: Vec<_> = at.resolved_runs().collect();
+
+        assert_eq!(
+            runs,
+            vec![
+                (TextRange::from(0..6), vec![&TestAttribute::A]),
+                (
+                    TextRange::from(6..11),
+                    vec![&TestAttribute::A, &TestAttribute::B]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolved_runs_for_range_clips_to_the_query() {
+        let mut at = AttributedText::new("Hello World");
+        at.apply_attribute(0..11, TestAttribute::A).unwrap();
+        at.apply_attribute(6..11, TestAttribute::B).unwrap();
+
+        let runs: Vec<_> = at.resolved_runs_for_range(3..8).collect();
+
+        assert_eq!(
+            runs,
+            vec![
+                (TextRange::from(3..6), vec![&TestAttribute::A]),
+                (
+                    TextRange::from(6..8),
+                    vec![&TestAttribute::A, &TestAttribute::B]
+                ),
+            ]
+        );
+    }
 }
@@ -0,0 +1,8 @@
+// Copyright 2025 the Styled Text Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [`EditBehavior`] and [`SpanEditAction`] are owned by the `attributed_text`
+//! crate; `styled_text` re-exports them here rather than keeping a second
+//! copy that could drift from the original.
+
+pub use attributed_text::{EditBehavior, SpanEditAction};
@@ -0,0 +1,143 @@
+// Copyright 2025 the Styled Text Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{TextRange, TextSize};
+
+/// A single insert/delete/replace operation, as part of a [`TextEdit`].
+///
+/// Modeled on `ra_text_edit::Indel` from rust-analyzer: `delete` is removed
+/// first, then `insert` is placed at `delete.start()`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Indel {
+    /// The range of text to remove.
+    ///
+    /// An empty range makes this a pure insertion.
+    pub delete: TextRange,
+    /// The text to insert at `delete.start()`.
+    ///
+    /// An empty string makes this a pure deletion.
+    pub insert: String,
+}
+
+impl Indel {
+    /// Insert `text` at `offset`, without deleting anything.
+    pub fn insert(offset: impl Into<TextSize>, text: String) -> Self {
+        let offset = offset.into();
+        Self {
+            delete: TextRange::empty(offset),
+            insert: text,
+        }
+    }
+
+    /// Delete `range`, without inserting anything.
+    pub fn delete(range: impl Into<TextRange>) -> Self {
+        Self {
+            delete: range.into(),
+            insert: String::new(),
+        }
+    }
+
+    /// Replace `range` with `text`.
+    pub fn replace(range: impl Into<TextRange>, text: String) -> Self {
+        Self {
+            delete: range.into(),
+            insert: text,
+        }
+    }
+
+    /// The signed change in text length this indel makes.
+    pub(crate) fn delta(&self) -> isize {
+        self.insert.len() as isize - self.delete.len().to_usize() as isize
+    }
+}
+
+/// The errors that might happen when building or [applying] a [`TextEdit`].
+///
+/// [applying]: crate::AttributedText::apply
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TextEditError {
+    /// Two or more indels in the edit have overlapping `delete` ranges.
+    OverlappingIndels,
+    /// An indel's `delete` range was out of bounds for the text it was
+    /// applied to.
+    OutOfBounds,
+}
+
+/// A set of non-overlapping [`Indel`]s that can be applied to an
+/// [`AttributedText`] as a single atomic operation.
+///
+/// [`AttributedText`]: crate::AttributedText
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextEdit {
+    indels: Vec<Indel>,
+}
+
+impl TextEdit {
+    /// Start building a [`TextEdit`] with a [`TextEditBuilder`].
+    pub fn builder() -> TextEditBuilder {
+        TextEditBuilder::default()
+    }
+
+    /// Merge `other` into this edit.
+    ///
+    /// Fails, leaving `self` unchanged, if the combined set of indels is no
+    /// longer pairwise non-overlapping.
+    pub fn union(&mut self, other: Self) -> Result<(), TextEditError> {
+        let mut indels = self.indels.clone();
+        indels.extend(other.indels);
+        self.indels = Self::validate_and_sort(indels)?;
+        Ok(())
+    }
+
+    pub(crate) fn indels(&self) -> &[Indel] {
+        &self.indels
+    }
+
+    fn validate_and_sort(mut indels: Vec<Indel>) -> Result<Vec<Indel>, TextEditError> {
+        indels.sort_by_key(|indel| indel.delete.start());
+        for pair in indels.windows(2) {
+            if pair[0].delete.end() > pair[1].delete.start() {
+                return Err(TextEditError::OverlappingIndels);
+            }
+        }
+        Ok(indels)
+    }
+}
+
+/// A builder for [`TextEdit`], accumulating [`Indel`]s before they're
+/// validated by [`finish`].
+///
+/// [`finish`]: TextEditBuilder::finish
+#[derive(Clone, Debug, Default)]
+pub struct TextEditBuilder {
+    indels: Vec<Indel>,
+}
+
+impl TextEditBuilder {
+    /// Record an insertion of `text` at `offset`.
+    pub fn insert(&mut self, offset: impl Into<TextSize>, text: String) {
+        self.indels.push(Indel::insert(offset, text));
+    }
+
+    /// Record the deletion of `range`.
+    pub fn delete(&mut self, range: impl Into<TextRange>) {
+        self.indels.push(Indel::delete(range));
+    }
+
+    /// Record the replacement of `range` with `text`.
+    pub fn replace(&mut self, range: impl Into<TextRange>, text: String) {
+        self.indels.push(Indel::replace(range, text));
+    }
+
+    /// Validate the recorded indels and produce a [`TextEdit`].
+    ///
+    /// Fails if any two recorded indels have overlapping `delete` ranges.
+    pub fn finish(self) -> Result<TextEdit, TextEditError> {
+        Ok(TextEdit {
+            indels: TextEdit::validate_and_sort(self.indels)?,
+        })
+    }
+}
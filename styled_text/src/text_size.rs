@@ -0,0 +1,225 @@
+// Copyright 2025 the Styled Text Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::fmt;
+use core::ops::{Add, AddAssign, Bound, Range, RangeBounds, Sub, SubAssign};
+
+/// A 32-bit, `char`-boundary-agnostic offset into a text buffer.
+///
+/// Storing offsets as `u32` rather than `usize` halves the size of every
+/// stored span, and routing all arithmetic through [`TextSize`] gives the
+/// library a single place to catch overflow and underflow instead of the
+/// ad-hoc `usize` subtraction previously scattered through span-adjustment
+/// code.
+///
+/// In the style of the `text-size` crate.
+#[derive(Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct TextSize {
+    raw: u32,
+}
+
+impl TextSize {
+    /// Create a `TextSize` from a raw `u32` offset.
+    pub const fn new(raw: u32) -> Self {
+        Self { raw }
+    }
+
+    /// The length of `text`, as a `TextSize`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `text` is longer than `u32::MAX` bytes.
+    pub fn of(text: &str) -> Self {
+        Self::from(text)
+    }
+
+    /// This offset as a `u32`.
+    pub const fn to_u32(self) -> u32 {
+        self.raw
+    }
+
+    /// This offset as a `usize`.
+    pub const fn to_usize(self) -> usize {
+        self.raw as usize
+    }
+
+    /// Add `rhs` to this offset, returning `None` on overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.raw.checked_add(rhs.raw).map(Self::new)
+    }
+
+    /// Subtract `rhs` from this offset, returning `None` on underflow.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.raw.checked_sub(rhs.raw).map(Self::new)
+    }
+}
+
+impl fmt::Debug for TextSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+impl From<u32> for TextSize {
+    fn from(raw: u32) -> Self {
+        Self::new(raw)
+    }
+}
+
+impl From<TextSize> for u32 {
+    fn from(size: TextSize) -> Self {
+        size.raw
+    }
+}
+
+impl From<usize> for TextSize {
+    /// # Panics
+    ///
+    /// Panics if `offset` doesn't fit in a `u32`.
+    fn from(offset: usize) -> Self {
+        Self::new(u32::try_from(offset).expect("text offset exceeds u32::MAX"))
+    }
+}
+
+impl From<TextSize> for usize {
+    fn from(size: TextSize) -> Self {
+        size.to_usize()
+    }
+}
+
+impl From<char> for TextSize {
+    fn from(c: char) -> Self {
+        Self::new(c.len_utf8() as u32)
+    }
+}
+
+impl From<&str> for TextSize {
+    fn from(text: &str) -> Self {
+        Self::from(text.len())
+    }
+}
+
+impl Add for TextSize {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).expect("TextSize overflow")
+    }
+}
+
+impl AddAssign for TextSize {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for TextSize {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).expect("TextSize underflow")
+    }
+}
+
+impl SubAssign for TextSize {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// A half-open `[start, end)` range of [`TextSize`] offsets, with the
+/// invariant `start <= end` enforced wherever one is constructed.
+///
+/// In the style of the `text-size` crate.
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+pub struct TextRange {
+    start: TextSize,
+    end: TextSize,
+}
+
+impl fmt::Debug for TextRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}..{:?}", self.start, self.end)
+    }
+}
+
+impl TextRange {
+    /// Create a `TextRange` covering `[start, end)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start > end`.
+    pub fn new(start: TextSize, end: TextSize) -> Self {
+        assert!(start <= end, "TextRange start must be <= end");
+        Self { start, end }
+    }
+
+    /// An empty range at `offset`.
+    pub fn empty(offset: TextSize) -> Self {
+        Self::new(offset, offset)
+    }
+
+    /// The start of the range.
+    pub const fn start(self) -> TextSize {
+        self.start
+    }
+
+    /// The end of the range.
+    pub const fn end(self) -> TextSize {
+        self.end
+    }
+
+    /// The length of the range.
+    pub fn len(self) -> TextSize {
+        self.end - self.start
+    }
+
+    /// Returns `true` if the range covers no offsets.
+    pub fn is_empty(self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns `true` if `offset` lies within this range.
+    pub fn contains(self, offset: TextSize) -> bool {
+        self.start <= offset && offset < self.end
+    }
+
+    /// Returns `true` if `other` lies entirely within this range.
+    pub fn contains_range(self, other: Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// The overlap between this range and `other`, if any.
+    pub fn intersect(self, other: Self) -> Option<Self> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+        (start <= end).then(|| Self::new(start, end))
+    }
+
+    /// The smallest range that contains both this range and `other`.
+    pub fn cover(self, other: Self) -> Self {
+        Self::new(self.start.min(other.start), self.end.max(other.end))
+    }
+}
+
+impl RangeBounds<TextSize> for TextRange {
+    fn start_bound(&self) -> Bound<&TextSize> {
+        Bound::Included(&self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&TextSize> {
+        Bound::Excluded(&self.end)
+    }
+}
+
+impl From<Range<usize>> for TextRange {
+    fn from(range: Range<usize>) -> Self {
+        Self::new(range.start.into(), range.end.into())
+    }
+}
+
+impl From<TextRange> for Range<usize> {
+    fn from(range: TextRange) -> Self {
+        range.start.to_usize()..range.end.to_usize()
+    }
+}
@@ -3,18 +3,20 @@
 
 use alloc::string::String;
 use alloc::sync::Arc;
-use core::ops::RangeBounds;
+use core::ops::Range;
+
+use crate::{TextRange, TextSize};
 
 /// A block of text that will be wrapped by an [`AttributedText`].
 ///
 /// [`AttributedText`]: crate::AttributedText
 pub trait TextStorage {
     /// The length of the underlying text.
-    fn len(&self) -> usize;
+    fn len(&self) -> TextSize;
 
     /// Return `true` if the underlying text is empty.
     fn is_empty(&self) -> bool {
-        self.len() == 0
+        self.len() == TextSize::new(0)
     }
 }
 
@@ -32,34 +34,29 @@ pub trait EditableTextStorage: TextStorage {
     ///
     /// Implementations of this should panic if the starting point or end
     /// point do not lie on a [`char`] boundary, or if they're out of bounds.
-    fn replace_range<R>(&mut self, range: R, replacement_text: &str)
-    where
-        R: RangeBounds<usize>;
+    fn replace_range(&mut self, range: TextRange, replacement_text: &str);
 }
 
 impl TextStorage for String {
-    fn len(&self) -> usize {
-        Self::len(self)
+    fn len(&self) -> TextSize {
+        TextSize::from(Self::len(self))
     }
 }
 
 impl TextStorage for &str {
-    fn len(&self) -> usize {
-        str::len(self)
+    fn len(&self) -> TextSize {
+        TextSize::from(str::len(self))
     }
 }
 
 impl TextStorage for Arc<str> {
-    fn len(&self) -> usize {
-        str::len(self)
+    fn len(&self) -> TextSize {
+        TextSize::from(str::len(self))
     }
 }
 
 impl EditableTextStorage for String {
-    fn replace_range<R>(&mut self, range: R, replacement_text: &str)
-    where
-        R: RangeBounds<usize>,
-    {
-        Self::replace_range(self, range, replacement_text);
+    fn replace_range(&mut self, range: TextRange, replacement_text: &str) {
+        Self::replace_range(self, Range::<usize>::from(range), replacement_text);
     }
 }